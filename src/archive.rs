@@ -0,0 +1,236 @@
+// src/archive.rs
+use crate::{FileSystemUtils, ProgressUpdate};
+use anyhow::{bail, Result};
+use std::{
+    path::{Component, Path, PathBuf},
+    time::Instant,
+};
+use tokio::fs::{self, File};
+use tokio_stream::StreamExt;
+use tokio_tar::{Archive, Builder};
+use walkdir::WalkDir;
+
+impl FileSystemUtils {
+    /// Pack every file under `src` into a tar archive at `archive`, preserving
+    /// paths relative to `src`. Streams entries with async I/O and reports
+    /// byte-level progress through the existing [`ProgressUpdate`] channel.
+    pub async fn create_archive(
+        src: &Path,
+        archive: &Path,
+        progress_sender: Option<tokio::sync::mpsc::Sender<ProgressUpdate>>,
+    ) -> Result<()> {
+        let mut total_bytes = 0u64;
+        let mut file_paths = Vec::new();
+        for entry in WalkDir::new(src).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() {
+                total_bytes += entry.metadata()?.len();
+                file_paths.push(entry.into_path());
+            }
+        }
+
+        if let Some(sender) = &progress_sender {
+            sender
+                .send(ProgressUpdate::Started {
+                    total_bytes,
+                    total_files: file_paths.len(),
+                })
+                .await?;
+        }
+
+        if let Some(parent) = archive.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let archive_file = File::create(archive).await?;
+        let mut builder = Builder::new(archive_file);
+
+        let start_time = Instant::now();
+        let mut bytes_processed = 0u64;
+        let mut files_processed = 0usize;
+        let total_files = file_paths.len();
+        for path in file_paths {
+            let relative = path.strip_prefix(src)?.to_path_buf();
+            let file_size = path.metadata()?.len();
+
+            builder.append_path_with_name(&path, &relative).await?;
+
+            bytes_processed += file_size;
+            files_processed += 1;
+            if let Some(sender) = &progress_sender {
+                let update = ProgressUpdate::progress(
+                    bytes_processed,
+                    total_bytes,
+                    Some(relative),
+                    files_processed,
+                    total_files,
+                    start_time,
+                );
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+        }
+
+        builder.finish().await?;
+
+        if let Some(sender) = progress_sender {
+            sender.send(ProgressUpdate::Completed).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Extract `archive` into `dst`, recreating the directory structure and
+    /// rejecting any entry whose normalized path would escape `dst` (e.g. via
+    /// `..` components or an absolute path). Reports byte-level progress
+    /// through the existing [`ProgressUpdate`] channel.
+    pub async fn extract_archive(
+        archive: &Path,
+        dst: &Path,
+        progress_sender: Option<tokio::sync::mpsc::Sender<ProgressUpdate>>,
+    ) -> Result<()> {
+        fs::create_dir_all(dst).await?;
+
+        let archive_file = File::open(archive).await?;
+        let total_bytes = archive_file.metadata().await?.len();
+        if let Some(sender) = &progress_sender {
+            sender
+                .send(ProgressUpdate::Started {
+                    total_bytes,
+                    total_files: 0,
+                })
+                .await?;
+        }
+
+        let mut ar = Archive::new(archive_file);
+        let mut entries = ar.entries()?;
+
+        let start_time = Instant::now();
+        let mut bytes_processed = 0u64;
+        let mut files_processed = 0usize;
+        while let Some(entry) = entries.next().await {
+            let mut entry = entry?;
+            let relative_path = entry.path()?.into_owned();
+            let dst_path = Self::safe_join(dst, &relative_path)?;
+
+            let entry_type = entry.header().entry_type();
+            if entry_type.is_symlink() || entry_type.is_hard_link() {
+                if let Some(link_name) = entry.link_name()? {
+                    Self::check_link_target_escape(dst, &dst_path, &link_name)?;
+                }
+            }
+
+            if let Some(parent) = dst_path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+
+            let entry_size = entry.header().size()?;
+            entry.unpack(&dst_path).await?;
+
+            bytes_processed += entry_size;
+            files_processed += 1;
+            if let Some(sender) = &progress_sender {
+                let update = ProgressUpdate::progress(
+                    bytes_processed,
+                    total_bytes,
+                    Some(relative_path),
+                    files_processed,
+                    files_processed,
+                    start_time,
+                );
+                let _ = sender.send(update).await;
+            }
+        }
+
+        if let Some(sender) = progress_sender {
+            sender.send(ProgressUpdate::Completed).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Join `relative` onto `dst`, rejecting components (`..`, an absolute
+    /// root, or a Windows prefix) that would let the resulting path escape
+    /// `dst`.
+    fn safe_join(dst: &Path, relative: &Path) -> Result<PathBuf> {
+        let mut out = dst.to_path_buf();
+        for component in relative.components() {
+            match component {
+                Component::Normal(part) => out.push(part),
+                Component::CurDir => {}
+                Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                    bail!("archive entry escapes destination: {}", relative.display());
+                }
+            }
+        }
+
+        if !out.starts_with(dst) {
+            bail!("archive entry escapes destination: {}", relative.display());
+        }
+
+        Ok(out)
+    }
+
+    /// Reject a symlink/hardlink entry whose `link_name` would resolve
+    /// outside `dst`. The target is resolved relative to `dst_path`'s parent
+    /// (matching how a symlink target is interpreted on disk), so a
+    /// safely-named entry pointing at e.g. `../../etc/passwd`, or at an
+    /// absolute path, is caught even though its own path passed
+    /// [`safe_join`](Self::safe_join).
+    fn check_link_target_escape(dst: &Path, dst_path: &Path, link_name: &Path) -> Result<()> {
+        if link_name.is_absolute() {
+            bail!(
+                "archive link entry points at an absolute path: {}",
+                link_name.display()
+            );
+        }
+
+        let mut resolved = dst_path.parent().unwrap_or(dst).to_path_buf();
+        for component in link_name.components() {
+            match component {
+                Component::Normal(part) => resolved.push(part),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    resolved.pop();
+                }
+                Component::RootDir | Component::Prefix(_) => {
+                    bail!("archive link entry escapes destination: {}", link_name.display());
+                }
+            }
+        }
+
+        if !resolved.starts_with(dst) {
+            bail!("archive link entry escapes destination: {}", link_name.display());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tokio_tar::{EntryType, Header};
+
+    #[tokio::test]
+    async fn extract_rejects_symlink_escaping_destination() {
+        let temp_dir = tempdir().unwrap();
+        let archive_path = temp_dir.path().join("evil.tar");
+        let dst = temp_dir.path().join("out");
+
+        let file = File::create(&archive_path).await.unwrap();
+        let mut builder = Builder::new(file);
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Symlink);
+        header.set_path("safely_named_entry").unwrap();
+        header.set_link_name("../../outside").unwrap();
+        header.set_size(0);
+        header.set_cksum();
+        builder.append(&header, &[][..]).await.unwrap();
+        builder.finish().await.unwrap();
+
+        let result = FileSystemUtils::extract_archive(&archive_path, &dst, None).await;
+        assert!(result.is_err());
+        assert!(!dst.join("safely_named_entry").exists());
+    }
+}
@@ -0,0 +1,251 @@
+// src/duplicates.rs
+use crate::{FileSystemUtils, ProgressUpdate};
+use anyhow::Result;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// How thoroughly to compare candidate duplicates. Each stage is strictly
+/// more expensive (and more certain) than the last: `Size` only reads
+/// directory metadata, `PartialHash` additionally hashes the first few KiB
+/// of each candidate, and `FullHash` reads every candidate byte-for-byte.
+/// Every hashing stage uses blake3; this selects how far the pipeline goes,
+/// not which digest it uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateMethod {
+    /// Group by file length alone. Fast but may report false positives.
+    Size,
+    /// Group by length, then by a hash of the first [`PREFIX_HASH_BYTES`] bytes.
+    PartialHash,
+    /// Group by length, then a prefix hash, then a full content hash.
+    FullHash,
+}
+
+/// Number of leading bytes hashed during the cheap prefix-hash stage.
+const PREFIX_HASH_BYTES: usize = 8 * 1024;
+
+impl FileSystemUtils {
+    /// Find groups of duplicate files under `root`, using the three-stage
+    /// size -> prefix-hash -> full-hash pipeline selected by `method`. Files
+    /// only collide into the same group once they agree at every stage up to
+    /// and including `method`. Progress is reported through the existing
+    /// [`ProgressUpdate`] channel as candidates are hashed.
+    pub async fn find_duplicates(
+        root: &Path,
+        method: DuplicateMethod,
+        progress_sender: Option<tokio::sync::mpsc::Sender<ProgressUpdate>>,
+    ) -> Result<Vec<Vec<PathBuf>>> {
+        // Stage 1: bucket every file by its length.
+        let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        let mut total_bytes = 0u64;
+        for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let len = entry.metadata()?.len();
+            total_bytes += len;
+            by_size.entry(len).or_default().push(entry.into_path());
+        }
+        by_size.retain(|_, paths| paths.len() > 1);
+
+        let candidate_count: usize = by_size.values().map(|v| v.len()).sum();
+        if let Some(sender) = &progress_sender {
+            sender
+                .send(ProgressUpdate::Started {
+                    total_bytes,
+                    total_files: candidate_count,
+                })
+                .await?;
+        }
+
+        if method == DuplicateMethod::Size {
+            if let Some(sender) = progress_sender {
+                sender.send(ProgressUpdate::Completed).await?;
+            }
+            return Ok(by_size.into_values().collect());
+        }
+
+        // Stage 2: within each size bucket, split further by a cheap prefix hash.
+        let start_time = std::time::Instant::now();
+        let mut hashed_processed = 0u64;
+        let mut by_prefix: HashMap<(u64, [u8; 32]), Vec<PathBuf>> = HashMap::new();
+        for (size, paths) in by_size {
+            for path in paths {
+                let hash = Self::hash_prefix(&path, PREFIX_HASH_BYTES)?;
+                hashed_processed += 1;
+                if let Some(sender) = &progress_sender {
+                    let update = ProgressUpdate::progress(
+                        hashed_processed,
+                        candidate_count as u64,
+                        Some(path.clone()),
+                        hashed_processed as usize,
+                        candidate_count,
+                        start_time,
+                    );
+                    let _ = sender.send(update).await;
+                }
+                by_prefix.entry((size, hash)).or_default().push(path);
+            }
+        }
+        by_prefix.retain(|_, paths| paths.len() > 1);
+
+        if method == DuplicateMethod::PartialHash {
+            if let Some(sender) = progress_sender {
+                sender.send(ProgressUpdate::Completed).await?;
+            }
+            return Ok(by_prefix.into_values().collect());
+        }
+
+        // Stage 3: surviving candidates are hashed in full.
+        let full_hash_candidates: usize = by_prefix.values().map(|v| v.len()).sum();
+        let mut full_hash_processed = 0u64;
+        let mut by_full: HashMap<blake3::Hash, Vec<PathBuf>> = HashMap::new();
+        for paths in by_prefix.into_values() {
+            for path in paths {
+                let hash = Self::hash_full(&path)?;
+                full_hash_processed += 1;
+                if let Some(sender) = &progress_sender {
+                    let update = ProgressUpdate::progress(
+                        full_hash_processed,
+                        full_hash_candidates as u64,
+                        Some(path.clone()),
+                        full_hash_processed as usize,
+                        full_hash_candidates,
+                        start_time,
+                    );
+                    let _ = sender.send(update).await;
+                }
+                by_full.entry(hash).or_default().push(path);
+            }
+        }
+        by_full.retain(|_, paths| paths.len() > 1);
+
+        if let Some(sender) = progress_sender {
+            sender.send(ProgressUpdate::Completed).await?;
+        }
+
+        Ok(by_full.into_values().collect())
+    }
+
+    /// Hash the first `limit` bytes of `path` with blake3.
+    fn hash_prefix(path: &Path, limit: usize) -> Result<[u8; 32]> {
+        let mut file = File::open(path)?;
+        let mut buffer = vec![0u8; limit];
+        let mut hasher = blake3::Hasher::new();
+        let mut remaining = limit;
+        while remaining > 0 {
+            let bytes_read = file.read(&mut buffer[..remaining])?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+            remaining -= bytes_read;
+        }
+        Ok(*hasher.finalize().as_bytes())
+    }
+
+    /// Hash the full contents of `path` with blake3.
+    pub(crate) fn hash_full(path: &Path) -> Result<blake3::Hash> {
+        let mut file = File::open(path)?;
+        let mut hasher = blake3::Hasher::new();
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let bytes_read = file.read(&mut buffer)?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+        }
+        Ok(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn full_hash_stage_reports_progress() {
+        let temp_dir = tempdir().unwrap();
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            std::fs::write(temp_dir.path().join(name), "same contents").unwrap();
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(32);
+        let groups = FileSystemUtils::find_duplicates(temp_dir.path(), DuplicateMethod::FullHash, Some(tx))
+            .await
+            .unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 3);
+
+        let mut progress_updates = 0;
+        while let Some(update) = rx.recv().await {
+            if matches!(update, ProgressUpdate::Progress { .. }) {
+                progress_updates += 1;
+            }
+        }
+        // 3 candidates hashed during stage 2 (prefix) AND again during stage 3
+        // (full) — silence during stage 3 would leave this at 3 instead of 6.
+        assert_eq!(progress_updates, 6);
+    }
+
+    #[tokio::test]
+    async fn size_method_groups_by_length_only() {
+        let temp_dir = tempdir().unwrap();
+        // Same length, different contents: Size still groups them together.
+        std::fs::write(temp_dir.path().join("a.txt"), "aaaa").unwrap();
+        std::fs::write(temp_dir.path().join("b.txt"), "bbbb").unwrap();
+        std::fs::write(temp_dir.path().join("c.txt"), "c").unwrap();
+
+        let groups = FileSystemUtils::find_duplicates(temp_dir.path(), DuplicateMethod::Size, None)
+            .await
+            .unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn partial_hash_method_splits_same_size_different_prefix() {
+        let temp_dir = tempdir().unwrap();
+        // Same length, different content: PartialHash splits them apart even
+        // though Size would have grouped them.
+        std::fs::write(temp_dir.path().join("a.txt"), "aaaa").unwrap();
+        std::fs::write(temp_dir.path().join("b.txt"), "bbbb").unwrap();
+        std::fs::write(temp_dir.path().join("c.txt"), "aaaa").unwrap();
+
+        let groups = FileSystemUtils::find_duplicates(temp_dir.path(), DuplicateMethod::PartialHash, None)
+            .await
+            .unwrap();
+        assert_eq!(groups.len(), 1);
+        let mut names: Vec<_> = groups[0]
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["a.txt", "c.txt"]);
+    }
+
+    #[tokio::test]
+    async fn zero_length_files_only_grouped_when_more_than_one_exists() {
+        let temp_dir = tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("empty1.txt"), "").unwrap();
+        std::fs::write(temp_dir.path().join("nonempty.txt"), "content").unwrap();
+
+        let groups = FileSystemUtils::find_duplicates(temp_dir.path(), DuplicateMethod::FullHash, None)
+            .await
+            .unwrap();
+        assert!(groups.is_empty());
+
+        std::fs::write(temp_dir.path().join("empty2.txt"), "").unwrap();
+        let groups = FileSystemUtils::find_duplicates(temp_dir.path(), DuplicateMethod::FullHash, None)
+            .await
+            .unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 2);
+    }
+}
@@ -0,0 +1,149 @@
+// src/ignore_rules.rs
+use glob::Pattern;
+use std::{cell::RefCell, fs, path::Path};
+use walkdir::{DirEntry, WalkDir};
+
+/// Exclude-pattern configuration consulted while walking a tree: an explicit
+/// set of glob patterns (checked against every entry, at every depth) plus
+/// optional `.gitignore` awareness, where each directory's own `.gitignore`
+/// extends the rules inherited from its parents.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreConfig {
+    patterns: Vec<String>,
+    use_gitignore: bool,
+}
+
+impl IgnoreConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add explicit glob exclude patterns (e.g. `target`, `*.tmp`, `target/`),
+    /// matched against both the entry's file name and its full path. A
+    /// trailing slash (the common `.gitignore` idiom for "this directory and
+    /// everything under it") restricts the pattern to directory entries.
+    pub fn with_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.patterns.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Honor `.gitignore` files found while descending the tree.
+    pub fn with_gitignore(mut self, enabled: bool) -> Self {
+        self.use_gitignore = enabled;
+        self
+    }
+}
+
+/// A compiled exclude pattern, tracking whether it came from a slash-suffixed
+/// entry (e.g. `target/`) and so only applies to directories.
+struct CompiledPattern {
+    pattern: Pattern,
+    dir_only: bool,
+}
+
+fn compile_pattern(raw: &str) -> Option<CompiledPattern> {
+    let dir_only = raw.ends_with('/');
+    let trimmed = raw.trim_end_matches('/');
+    Pattern::new(trimmed)
+        .ok()
+        .map(|pattern| CompiledPattern { pattern, dir_only })
+}
+
+/// Walk `root`, pruning any entry (and, for directories, its whole subtree)
+/// matched by `ignore`. Rules are tracked as a per-directory stack built
+/// while descending: the explicit patterns apply everywhere, and each
+/// directory's own `.gitignore` (when enabled) extends the rules already in
+/// force for its parent, so nearer rules layer on top of farther ones.
+pub(crate) fn walk_filtered(root: &Path, ignore: &IgnoreConfig) -> Vec<DirEntry> {
+    let root_patterns: Vec<CompiledPattern> = ignore
+        .patterns
+        .iter()
+        .filter_map(|p| compile_pattern(p))
+        .collect();
+
+    // Each stack frame is `(depth_it_applies_from, patterns)`; frames are
+    // popped once the walk backs out above the depth they were pushed at.
+    let stack: RefCell<Vec<(usize, Vec<CompiledPattern>)>> =
+        RefCell::new(vec![(0, root_patterns)]);
+
+    WalkDir::new(root)
+        .into_iter()
+        .filter_entry(move |entry| {
+            let mut stack = stack.borrow_mut();
+            stack.retain(|(depth, _)| *depth <= entry.depth());
+
+            let ignored = stack
+                .iter()
+                .flat_map(|(_, patterns)| patterns.iter())
+                .any(|pattern| entry_matches(pattern, entry));
+
+            if !ignored && entry.file_type().is_dir() && ignore.use_gitignore {
+                stack.push((entry.depth() + 1, load_gitignore(entry.path())));
+            }
+
+            !ignored
+        })
+        .filter_map(|e| e.ok())
+        .collect()
+}
+
+fn entry_matches(pattern: &CompiledPattern, entry: &DirEntry) -> bool {
+    if pattern.dir_only && !entry.file_type().is_dir() {
+        return false;
+    }
+
+    let file_name = entry.file_name().to_string_lossy();
+    pattern.pattern.matches(&file_name) || pattern.pattern.matches(&entry.path().to_string_lossy())
+}
+
+fn load_gitignore(dir: &Path) -> Vec<CompiledPattern> {
+    let Ok(contents) = fs::read_to_string(dir.join(".gitignore")) else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(compile_pattern)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FileSystemUtils;
+    use tempfile::tempdir;
+
+    #[test]
+    fn gitignore_trailing_slash_excludes_whole_directory() {
+        let temp_dir = tempdir().unwrap();
+        std::fs::write(temp_dir.path().join(".gitignore"), "target/\n").unwrap();
+        std::fs::create_dir(temp_dir.path().join("target")).unwrap();
+        std::fs::write(temp_dir.path().join("target").join("build_output.o"), "").unwrap();
+        std::fs::write(temp_dir.path().join("keep.txt"), "").unwrap();
+
+        let ignore = IgnoreConfig::new().with_gitignore(true);
+        let files = FileSystemUtils::find_files_filtered(temp_dir.path(), None, true, &ignore).unwrap();
+
+        assert!(files.iter().any(|p| p.ends_with("keep.txt")));
+        assert!(!files.iter().any(|p| p.ends_with("build_output.o")));
+    }
+
+    #[test]
+    fn trailing_slash_pattern_does_not_match_a_file_of_the_same_name() {
+        let temp_dir = tempdir().unwrap();
+        // A file literally named "target" should NOT be excluded by "target/",
+        // since that pattern is restricted to directory entries.
+        std::fs::write(temp_dir.path().join("target"), "").unwrap();
+
+        let ignore = IgnoreConfig::new().with_patterns(["target/"]);
+        let files = FileSystemUtils::find_files_filtered(temp_dir.path(), None, true, &ignore).unwrap();
+
+        assert!(files.iter().any(|p| p.ends_with("target")));
+    }
+}
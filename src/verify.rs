@@ -0,0 +1,204 @@
+// src/verify.rs
+use crate::{FileSystemUtils, ProgressUpdate};
+use anyhow::{Context, Result};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use tokio::task;
+use walkdir::WalkDir;
+
+/// Name of the manifest file a verified copy leaves behind in its
+/// destination root, so a later [`scrub`](FileSystemUtils::scrub) can
+/// recheck the tree without needing the original source around.
+const MANIFEST_FILE_NAME: &str = ".fs_utilities_manifest";
+
+/// Relative-path -> blake3 digest mapping recorded by
+/// [`copy_directory_verified`](FileSystemUtils::copy_directory_verified) and
+/// consulted by [`scrub`](FileSystemUtils::scrub).
+#[derive(Debug, Default)]
+struct Manifest {
+    hashes: HashMap<PathBuf, blake3::Hash>,
+}
+
+impl Manifest {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = String::new();
+        for (path, hash) in &self.hashes {
+            out.push_str(&format!("{}\t{}\n", hash.to_hex(), path.display()));
+        }
+        out.into_bytes()
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut hashes = HashMap::new();
+        for line in contents.lines() {
+            let Some((hex, path)) = line.split_once('\t') else {
+                continue;
+            };
+            if let Ok(hash) = blake3::Hash::from_hex(hex) {
+                hashes.insert(PathBuf::from(path), hash);
+            }
+        }
+        Manifest { hashes }
+    }
+}
+
+/// Report produced by [`copy_directory_verified`](FileSystemUtils::copy_directory_verified):
+/// files whose copied content still didn't match the source after exhausting
+/// `retry_limit` attempts.
+#[derive(Debug, Clone, Default)]
+pub struct VerifyReport {
+    pub mismatches: Vec<PathBuf>,
+}
+
+/// Report produced by [`scrub`](FileSystemUtils::scrub): files that no
+/// longer match their recorded manifest hash, and files the manifest expects
+/// but that are missing from disk.
+#[derive(Debug, Clone, Default)]
+pub struct ScrubReport {
+    pub corrupted: Vec<PathBuf>,
+    pub missing: Vec<PathBuf>,
+}
+
+impl FileSystemUtils {
+    /// Copy `src` into `dst` like [`copy_directory_with_progress`](Self::copy_directory_with_progress),
+    /// but verify each file afterwards by re-reading the destination and
+    /// comparing its content hash against the source, re-copying (up to
+    /// `retry_limit` times) any file that fails. A manifest of source hashes
+    /// is left behind at `dst`'s root so [`scrub`](Self::scrub) can recheck
+    /// the tree later. Returns a report of any files that still mismatched
+    /// once retries were exhausted.
+    pub async fn copy_directory_verified(
+        src: &Path,
+        dst: &Path,
+        retry_limit: usize,
+        progress_sender: Option<tokio::sync::mpsc::Sender<ProgressUpdate>>,
+    ) -> Result<VerifyReport> {
+        Self::copy_directory_with_progress(src, dst, progress_sender).await?;
+
+        let mut manifest = Manifest::default();
+        let mut mismatches = Vec::new();
+
+        for entry in WalkDir::new(src).into_iter().filter_map(|e| e.ok()) {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let src_path = entry.into_path();
+            let relative_path = src_path.strip_prefix(src)?.to_path_buf();
+            let dst_path = dst.join(&relative_path);
+
+            let src_hash = {
+                let src_path = src_path.clone();
+                task::spawn_blocking(move || Self::hash_full(&src_path)).await??
+            };
+
+            let mut attempts_left = retry_limit;
+            loop {
+                let dst_hash = {
+                    let dst_path = dst_path.clone();
+                    task::spawn_blocking(move || Self::hash_full(&dst_path)).await?
+                };
+
+                match dst_hash {
+                    Ok(hash) if hash == src_hash => break,
+                    _ if attempts_left == 0 => {
+                        mismatches.push(relative_path.clone());
+                        break;
+                    }
+                    _ => {
+                        attempts_left -= 1;
+                        Self::copy_file_with_progress_atomic(&src_path, &dst_path, None).await?;
+                    }
+                }
+            }
+
+            manifest.hashes.insert(relative_path, src_hash);
+        }
+
+        Self::atomic_write(&dst.join(MANIFEST_FILE_NAME), &manifest.to_bytes()).await?;
+
+        Ok(VerifyReport { mismatches })
+    }
+
+    /// Walk a previously [`copy_directory_verified`](Self::copy_directory_verified)
+    /// tree at `root`, recompute each file's hash, and compare it against the
+    /// manifest left behind at `root`/`.fs_utilities_manifest`. Returns the
+    /// files that no longer match (corrupted) and the files the manifest
+    /// expects but that are no longer present (missing).
+    pub async fn scrub(root: &Path) -> Result<ScrubReport> {
+        let manifest_path = root.join(MANIFEST_FILE_NAME);
+        let contents = tokio::fs::read_to_string(&manifest_path)
+            .await
+            .with_context(|| format!("reading manifest at {}", manifest_path.display()))?;
+        let manifest = Manifest::parse(&contents);
+
+        let mut report = ScrubReport::default();
+        for (relative_path, expected_hash) in manifest.hashes {
+            let path = root.join(&relative_path);
+            if !path.exists() {
+                report.missing.push(relative_path);
+                continue;
+            }
+
+            let actual_hash = {
+                let path = path.clone();
+                task::spawn_blocking(move || Self::hash_full(&path)).await??
+            };
+
+            if actual_hash != expected_hash {
+                report.corrupted.push(relative_path);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn verified_copy_then_scrub_reports_no_problems() {
+        let temp_dir = tempdir().unwrap();
+        let src = temp_dir.path().join("src");
+        let dst = temp_dir.path().join("dst");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("a.txt"), "hello").unwrap();
+        std::fs::write(src.join("b.txt"), "world").unwrap();
+
+        let report = FileSystemUtils::copy_directory_verified(&src, &dst, 2, None)
+            .await
+            .unwrap();
+        assert!(report.mismatches.is_empty());
+
+        let scrub_report = FileSystemUtils::scrub(&dst).await.unwrap();
+        assert!(scrub_report.corrupted.is_empty());
+        assert!(scrub_report.missing.is_empty());
+    }
+
+    #[tokio::test]
+    async fn scrub_detects_corruption_and_missing_files() {
+        let temp_dir = tempdir().unwrap();
+        let src = temp_dir.path().join("src");
+        let dst = temp_dir.path().join("dst");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("a.txt"), "hello").unwrap();
+        std::fs::write(src.join("b.txt"), "world").unwrap();
+
+        FileSystemUtils::copy_directory_verified(&src, &dst, 2, None)
+            .await
+            .unwrap();
+
+        // Corrupt one copied file and delete another.
+        std::fs::write(dst.join("a.txt"), "corrupted!").unwrap();
+        std::fs::remove_file(dst.join("b.txt")).unwrap();
+
+        let scrub_report = FileSystemUtils::scrub(&dst).await.unwrap();
+        assert_eq!(scrub_report.corrupted, vec![PathBuf::from("a.txt")]);
+        assert_eq!(scrub_report.missing, vec![PathBuf::from("b.txt")]);
+    }
+}